@@ -0,0 +1,116 @@
+use dirs::config_dir;
+use rusqlite::{Connection, OptionalExtension};
+use std::{
+    env,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const CACHE_DB_NAME: &str = "yaf-cache.db";
+const DEFAULT_TTL_SECS: u64 = 300;
+
+/// Returns a cached count for `source` if younger than the TTL, else runs `compute` and caches it.
+pub fn fetch_or_cache<F>(source: &str, compute: F) -> usize
+where
+    F: FnOnce() -> usize,
+{
+    let conn = match open_cache_db() {
+        Some(conn) => conn,
+        None => return compute(),
+    };
+
+    let now = current_timestamp();
+    if let Ok(Some((count, _, updated_at))) = read_cached_row(&conn, source) {
+        if now.saturating_sub(updated_at) < cache_ttl() {
+            return count;
+        }
+    }
+
+    let count = compute();
+    let _ = upsert_row(&conn, source, count, None, now);
+    count
+}
+
+/// Like [`fetch_or_cache`], but caches a `(count, extra)` pair as a single row from one compute.
+pub fn fetch_or_cache_pair<F>(source: &str, compute: F) -> (usize, usize)
+where
+    F: FnOnce() -> (usize, usize),
+{
+    let conn = match open_cache_db() {
+        Some(conn) => conn,
+        None => return compute(),
+    };
+
+    let now = current_timestamp();
+    if let Ok(Some((count, Some(extra), updated_at))) = read_cached_row(&conn, source) {
+        if now.saturating_sub(updated_at) < cache_ttl() {
+            return (count, extra);
+        }
+    }
+
+    let (count, extra) = compute();
+    let _ = upsert_row(&conn, source, count, Some(extra), now);
+    (count, extra)
+}
+
+fn open_cache_db() -> Option<Connection> {
+    let path = config_dir()?.join(CACHE_DB_NAME);
+    let conn = Connection::open(path).ok()?;
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS pkg_counts (
+            source TEXT PRIMARY KEY,
+            count INTEGER NOT NULL,
+            extra INTEGER,
+            updated_at INTEGER NOT NULL
+        )",
+        [],
+    )
+    .ok()?;
+    Some(conn)
+}
+
+fn read_cached_row(
+    conn: &Connection,
+    source: &str,
+) -> rusqlite::Result<Option<(usize, Option<usize>, u64)>> {
+    conn.query_row(
+        "SELECT count, extra, updated_at FROM pkg_counts WHERE source = ?1",
+        [source],
+        |row| {
+            Ok((
+                row.get::<_, i64>(0)? as usize,
+                row.get::<_, Option<i64>>(1)?.map(|extra| extra as usize),
+                row.get::<_, i64>(2)? as u64,
+            ))
+        },
+    )
+    .optional()
+}
+
+fn upsert_row(
+    conn: &Connection,
+    source: &str,
+    count: usize,
+    extra: Option<usize>,
+    updated_at: u64,
+) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO pkg_counts (source, count, extra, updated_at) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(source) DO UPDATE SET count = excluded.count, extra = excluded.extra, updated_at = excluded.updated_at",
+        rusqlite::params![source, count as i64, extra.map(|e| e as i64), updated_at as i64],
+    )?;
+    Ok(())
+}
+
+fn cache_ttl() -> u64 {
+    env::var("YAF_CACHE_TTL")
+        .ok()
+        .and_then(|ttl| ttl.parse().ok())
+        .unwrap_or(DEFAULT_TTL_SECS)
+}
+
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
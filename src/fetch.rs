@@ -1,13 +1,5 @@
-use crate::{ERROR_STR, NOT_AVAILABLE_STR};
-use std::{
-    env,
-    fs::{read_dir, File},
-    io::Read,
-    path::Path,
-    process::Command,
-    thread,
-    time::Duration,
-};
+use crate::NOT_AVAILABLE_STR;
+use std::{env, fs::File, io::Read, path::Path, time::Duration};
 use whoami::fallible::{distro, hostname, username};
 
 pub fn get_distro() -> String {
@@ -41,101 +33,6 @@ pub fn get_kernel() -> String {
     }
 }
 
-pub fn get_pkgs() -> String {
-    let home = match env::var("HOME") {
-        Ok(p) => p,
-        Err(_) => return String::from(ERROR_STR),
-    };
-
-    let pacman_count = read_dir("/var/lib/pacman/local")
-        .map(|entries| entries.count())
-        .unwrap_or(0);
-
-    let xbps_count = read_dir("/var/db/xbps")
-        .map(|entries| entries.count())
-        .unwrap_or(0);
-
-    let apt_count = read_dir("/var/lib/dpkg/info")
-        .map(|entries| {
-            entries
-                .filter_map(|entry| entry.ok())
-                .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "list"))
-                .count()
-        })
-        .unwrap_or(0);
-
-    let flatpak_count = {
-        let system_count = read_dir("/var/lib/flatpak/app")
-            .map(|entries| entries.count())
-            .unwrap_or(0);
-
-        let user_count = read_dir(String::from(&home) + "/.local/share/flatpak/app")
-            .map(|entries| entries.count())
-            .unwrap_or(0);
-
-        system_count + user_count
-    };
-
-    let nix_count = {
-        let system_handle = thread::spawn(move || {
-            Command::new("nix-store")
-                .args(["--query", "--requisites", "/run/current-system"])
-                .output()
-                .ok()
-                .and_then(|output| {
-                    String::from_utf8_lossy(&output.stdout)
-                        .lines()
-                        .count()
-                        .into()
-                })
-                .unwrap_or(0)
-        });
-
-        let user_handle = thread::spawn(move || {
-            Command::new("nix-store")
-                .args([
-                    "--query",
-                    "--requisites",
-                    &(String::from(&home) + "/.nix-profile"),
-                ])
-                .output()
-                .ok()
-                .and_then(|output| {
-                    String::from_utf8_lossy(&output.stdout)
-                        .lines()
-                        .count()
-                        .into()
-                })
-                .unwrap_or(0)
-        });
-
-        system_handle.join().unwrap() + user_handle.join().unwrap()
-    };
-
-    let mut output = Vec::new();
-    if pacman_count != 0 {
-        output.push(format!("{} (pacman)", pacman_count));
-    }
-    if xbps_count != 0 {
-        output.push(format!("{} (xbps)", xbps_count));
-    }
-    if apt_count != 0 {
-        output.push(format!("{} (apt)", apt_count));
-    }
-    if flatpak_count != 0 {
-        output.push(format!("{} (flatpak)", flatpak_count));
-    }
-    if nix_count != 0 {
-        output.push(format!("{} (nix)", nix_count));
-    }
-
-    if output.is_empty() {
-        return String::from(NOT_AVAILABLE_STR);
-    }
-
-    output.join(", ")
-}
-
 pub fn get_shell() -> String {
     match env::var("SHELL") {
         Ok(x) => String::from(x.rsplit('/').next().unwrap()),
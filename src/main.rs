@@ -1,8 +1,11 @@
+mod cache;
 mod fetch;
+mod pkgs;
 
 use argp::{help::HelpStyle, FromArgs};
 use dirs::config_dir;
 use fetch::*;
+use pkgs::get_pkgs;
 use std::{
     env,
     fs::File,
@@ -158,15 +161,69 @@ fn parse_var(var: &str) -> Result<String, ConfigError> {
         _ if var.starts_with('@') => Ok(replace_var(&var[1..])?),
         _ if var.starts_with('$') => Ok(get_env(&var[1..])),
         _ if var.starts_with('#') => Ok(run_sh(&var[1..])),
-        _ if var.starts_with('c') => Ok(var[1..]
-            .trim()
-            .parse::<u8>()
-            .map(|c| format!("\x1B[38;5;{}m", c))
-            .map_err(|_| ConfigError::UnknownVariable(String::from(var)))?),
+        _ if var.starts_with("bg") => parse_color(&var[2..], true)
+            .ok_or_else(|| ConfigError::UnknownVariable(String::from(var))),
+        _ if var.starts_with('c') => parse_color(&var[1..], false)
+            .ok_or_else(|| ConfigError::UnknownVariable(String::from(var))),
+        "bold" => Ok(String::from("\x1B[1m")),
+        "dim" => Ok(String::from("\x1B[2m")),
+        "italic" => Ok(String::from("\x1B[3m")),
+        "underline" => Ok(String::from("\x1B[4m")),
+        "reset" => Ok(String::from("\x1B[0m")),
         _ => Err(ConfigError::UnknownVariable(String::from(var))),
     }
 }
 
+/// Parses a `#RRGGBB` hex, a `<n>` 256-color index, or a named color.
+fn parse_color(arg: &str, is_bg: bool) -> Option<String> {
+    let arg = arg.trim();
+    let layer = if is_bg { 48 } else { 38 };
+
+    if let Some(hex) = arg.strip_prefix('#') {
+        let (r, g, b) = parse_hex_rgb(hex)?;
+        return Some(format!("\x1B[{};2;{};{};{}m", layer, r, g, b));
+    }
+
+    if let Ok(index) = arg.parse::<u8>() {
+        return Some(format!("\x1B[{};5;{}m", layer, index));
+    }
+
+    let code = named_color_code(arg)?;
+    Some(format!("\x1B[{}m", if is_bg { code + 10 } else { code }))
+}
+
+fn parse_hex_rgb(hex: &str) -> Option<(u8, u8, u8)> {
+    if hex.chars().count() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some((r, g, b))
+}
+
+fn named_color_code(name: &str) -> Option<u8> {
+    let (bright, name) = match name.strip_prefix("bright") {
+        Some(rest) => (true, rest.trim()),
+        None => (false, name),
+    };
+
+    let base = match name {
+        "black" => 30,
+        "red" => 31,
+        "green" => 32,
+        "yellow" => 33,
+        "blue" => 34,
+        "magenta" => 35,
+        "cyan" => 36,
+        "white" => 37,
+        _ => return None,
+    };
+
+    Some(if bright { base + 60 } else { base })
+}
+
 fn replace_var(key: &str) -> Result<String, ConfigError> {
     if !BUILTIN_VARS.contains(&key) {
         return Err(ConfigError::UnknownVariable(String::from(key)));
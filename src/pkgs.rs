@@ -0,0 +1,267 @@
+use crate::{
+    cache::{fetch_or_cache, fetch_or_cache_pair},
+    ERROR_STR, NOT_AVAILABLE_STR,
+};
+use std::{
+    env,
+    fs::{read_dir, read_to_string},
+    process::Command,
+    thread,
+};
+
+/// A package manager's count, optionally broken down by install reason.
+enum PkgCount {
+    Total(usize),
+    WithExplicit { total: usize, explicit: usize },
+}
+
+impl PkgCount {
+    fn is_empty(&self) -> bool {
+        match self {
+            PkgCount::Total(total) => *total == 0,
+            PkgCount::WithExplicit { total, .. } => *total == 0,
+        }
+    }
+
+    fn format(&self, name: &str) -> String {
+        match self {
+            PkgCount::Total(total) => format!("{} ({})", total, name),
+            PkgCount::WithExplicit { total, explicit } => {
+                format!("{} ({}, {} explicit)", total, name, explicit)
+            }
+        }
+    }
+}
+
+/// A display name plus the closure used to count its installed packages.
+struct PkgDetector {
+    name: &'static str,
+    count: fn(&str) -> PkgCount,
+}
+
+const DETECTORS: &[PkgDetector] = &[
+    PkgDetector {
+        name: "pacman",
+        count: count_pacman,
+    },
+    PkgDetector {
+        name: "xbps",
+        count: count_xbps,
+    },
+    PkgDetector {
+        name: "apt",
+        count: count_apt,
+    },
+    PkgDetector {
+        name: "flatpak",
+        count: count_flatpak,
+    },
+    PkgDetector {
+        name: "nix",
+        count: count_nix,
+    },
+    PkgDetector {
+        name: "dnf",
+        count: count_dnf,
+    },
+    PkgDetector {
+        name: "portage",
+        count: count_portage,
+    },
+    PkgDetector {
+        name: "snap",
+        count: count_snap,
+    },
+    PkgDetector {
+        name: "cargo",
+        count: count_cargo,
+    },
+    PkgDetector {
+        name: "brew",
+        count: count_brew,
+    },
+];
+
+pub fn get_pkgs() -> String {
+    let home = match env::var("HOME") {
+        Ok(p) => p,
+        Err(_) => return String::from(ERROR_STR),
+    };
+
+    let output: Vec<String> = DETECTORS
+        .iter()
+        .map(|detector| (detector, (detector.count)(&home)))
+        .filter(|(_, count)| !count.is_empty())
+        .map(|(detector, count)| count.format(detector.name))
+        .collect();
+
+    if output.is_empty() {
+        return String::from(NOT_AVAILABLE_STR);
+    }
+
+    output.join(", ")
+}
+
+fn count_pacman(_home: &str) -> PkgCount {
+    let (total, explicit) = fetch_or_cache_pair("pacman", scan_pacman);
+    PkgCount::WithExplicit { total, explicit }
+}
+
+/// Walks `/var/lib/pacman/local`, returning `(total, explicit)`.
+fn scan_pacman() -> (usize, usize) {
+    let mut total = 0;
+    let mut explicit = 0;
+
+    if let Ok(entries) = read_dir("/var/lib/pacman/local") {
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            total += 1;
+            if read_to_string(entry.path().join("desc"))
+                .map(|desc| is_explicit(&desc))
+                .unwrap_or(true)
+            {
+                explicit += 1;
+            }
+        }
+    }
+
+    (total, explicit)
+}
+
+fn is_explicit(desc: &str) -> bool {
+    desc.lines()
+        .skip_while(|line| *line != "%REASON%")
+        .nth(1)
+        .map(|reason| reason.trim() == "0")
+        .unwrap_or(true)
+}
+
+fn count_xbps(_home: &str) -> PkgCount {
+    PkgCount::Total(fetch_or_cache("xbps", || {
+        read_dir("/var/db/xbps")
+            .map(|entries| entries.count())
+            .unwrap_or(0)
+    }))
+}
+
+fn count_apt(_home: &str) -> PkgCount {
+    PkgCount::Total(fetch_or_cache("apt", || {
+        read_dir("/var/lib/dpkg/info")
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "list"))
+                    .count()
+            })
+            .unwrap_or(0)
+    }))
+}
+
+fn count_flatpak(home: &str) -> PkgCount {
+    let home = home.to_string();
+    PkgCount::Total(fetch_or_cache("flatpak", || {
+        let system_count = read_dir("/var/lib/flatpak/app")
+            .map(|entries| entries.count())
+            .unwrap_or(0);
+
+        let user_count = read_dir(home + "/.local/share/flatpak/app")
+            .map(|entries| entries.count())
+            .unwrap_or(0);
+
+        system_count + user_count
+    }))
+}
+
+fn count_nix(home: &str) -> PkgCount {
+    let home = home.to_string();
+    PkgCount::Total(fetch_or_cache("nix", || {
+        let system_handle = thread::spawn(move || {
+            Command::new("nix-store")
+                .args(["--query", "--requisites", "/run/current-system"])
+                .output()
+                .ok()
+                .and_then(|output| {
+                    String::from_utf8_lossy(&output.stdout)
+                        .lines()
+                        .count()
+                        .into()
+                })
+                .unwrap_or(0)
+        });
+
+        let user_handle = thread::spawn(move || {
+            Command::new("nix-store")
+                .args(["--query", "--requisites", &(home + "/.nix-profile")])
+                .output()
+                .ok()
+                .and_then(|output| {
+                    String::from_utf8_lossy(&output.stdout)
+                        .lines()
+                        .count()
+                        .into()
+                })
+                .unwrap_or(0)
+        });
+
+        system_handle.join().unwrap() + user_handle.join().unwrap()
+    }))
+}
+
+fn count_dnf(_home: &str) -> PkgCount {
+    PkgCount::Total(fetch_or_cache("dnf", || {
+        Command::new("rpm")
+            .arg("-qa")
+            .output()
+            .ok()
+            .map(|output| String::from_utf8_lossy(&output.stdout).lines().count())
+            .unwrap_or(0)
+    }))
+}
+
+fn count_portage(_home: &str) -> PkgCount {
+    PkgCount::Total(fetch_or_cache("portage", || {
+        read_dir("/var/db/pkg")
+            .map(|categories| {
+                categories
+                    .filter_map(|category| category.ok())
+                    .filter_map(|category| read_dir(category.path()).ok())
+                    .map(|packages| packages.count())
+                    .sum()
+            })
+            .unwrap_or(0)
+    }))
+}
+
+fn count_snap(_home: &str) -> PkgCount {
+    PkgCount::Total(fetch_or_cache("snap", || {
+        Command::new("snap")
+            .arg("list")
+            .output()
+            .ok()
+            .map(|output| {
+                String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .skip(1)
+                    .count()
+            })
+            .unwrap_or(0)
+    }))
+}
+
+fn count_cargo(home: &str) -> PkgCount {
+    let home = home.to_string();
+    PkgCount::Total(fetch_or_cache("cargo", || {
+        read_dir(home + "/.cargo/bin")
+            .map(|entries| entries.count())
+            .unwrap_or(0)
+    }))
+}
+
+fn count_brew(_home: &str) -> PkgCount {
+    PkgCount::Total(fetch_or_cache("brew", || {
+        read_dir("/home/linuxbrew/.linuxbrew/Cellar")
+            .or_else(|_| read_dir("/opt/homebrew/Cellar"))
+            .or_else(|_| read_dir("/usr/local/Cellar"))
+            .map(|entries| entries.count())
+            .unwrap_or(0)
+    }))
+}